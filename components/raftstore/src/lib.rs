@@ -0,0 +1,8 @@
+// Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
+
+#[macro_use]
+extern crate tikv_util;
+
+pub mod store;
+
+pub type Result<T> = std::result::Result<T, tikv_util::Error>;
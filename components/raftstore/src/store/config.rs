@@ -0,0 +1,115 @@
+// Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use tikv_util::config::{ReadableDuration, ReadableSize};
+
+use crate::Result;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+#[serde(rename_all = "kebab-case")]
+pub struct Config {
+    pub raft_base_tick_interval: ReadableDuration,
+    pub raft_election_timeout_ticks: usize,
+    pub raft_log_gc_tick_interval: ReadableDuration,
+    pub raft_log_gc_threshold: u64,
+    pub raft_log_gc_count_limit: u64,
+    pub raft_log_gc_size_limit: ReadableSize,
+    pub merge_max_log_gap: u64,
+
+    /// How long a follower can go without hearing from its leader before it treats the
+    /// leader as abnormal and starts verifying with PD whether the leader is still
+    /// valid. While a region is hibernating this (and `max_leader_missing_duration`) is
+    /// checked instead of the usual raft election timeout, since the leader has stopped
+    /// sending heartbeats on purpose.
+    pub abnormal_leader_missing_duration: ReadableDuration,
+
+    /// The upper bound: past this much silence a follower gives up waiting on the
+    /// hibernating leader and starts campaigning on its own, so hibernation can't be
+    /// mistaken for a real outage indefinitely.
+    pub max_leader_missing_duration: ReadableDuration,
+
+    /// How often a peer re-checks leader liveness against `abnormal_leader_missing_duration`/
+    /// `max_leader_missing_duration` while hibernating. Must be shorter than
+    /// `abnormal_leader_missing_duration` so a genuinely missing leader is still caught
+    /// promptly instead of only being noticed after the fact.
+    pub peer_stale_state_check_interval: ReadableDuration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            raft_base_tick_interval: ReadableDuration::secs(1),
+            raft_election_timeout_ticks: 10,
+            raft_log_gc_tick_interval: ReadableDuration::secs(10),
+            raft_log_gc_threshold: 50,
+            raft_log_gc_count_limit: 72_000,
+            raft_log_gc_size_limit: ReadableSize::mb(72),
+            merge_max_log_gap: 10,
+            abnormal_leader_missing_duration: ReadableDuration::secs(10 * 60),
+            max_leader_missing_duration: ReadableDuration::secs(2 * 60 * 60),
+            peer_stale_state_check_interval: ReadableDuration::secs(5 * 60),
+        }
+    }
+}
+
+impl Config {
+    pub fn validate(&self) -> Result<()> {
+        if self.raft_base_tick_interval.0 == Duration::from_secs(0) {
+            return Err(box_err!("raft-base-tick-interval can't be zero"));
+        }
+        if self.peer_stale_state_check_interval >= self.abnormal_leader_missing_duration {
+            return Err(box_err!(
+                "peer-stale-state-check-interval {:?} must be smaller than \
+                 abnormal-leader-missing-duration {:?}, otherwise a missing leader \
+                 wouldn't be caught until well after the threshold has passed",
+                self.peer_stale_state_check_interval,
+                self.abnormal_leader_missing_duration
+            ));
+        }
+        if self.abnormal_leader_missing_duration > self.max_leader_missing_duration {
+            return Err(box_err!(
+                "abnormal-leader-missing-duration {:?} must be <= \
+                 max-leader-missing-duration {:?}",
+                self.abnormal_leader_missing_duration,
+                self.max_leader_missing_duration
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_is_valid() {
+        Config::default().validate().unwrap();
+    }
+
+    #[test]
+    fn test_stale_state_check_must_be_shorter_than_abnormal_missing() {
+        let mut cfg = Config::default();
+        // Equal is rejected too: the check needs to run strictly more often than the
+        // threshold it's watching for, not just as often.
+        cfg.peer_stale_state_check_interval = cfg.abnormal_leader_missing_duration;
+        assert!(cfg.validate().is_err());
+
+        cfg.peer_stale_state_check_interval = ReadableDuration::secs(
+            cfg.abnormal_leader_missing_duration.as_secs() + 1,
+        );
+        assert!(cfg.validate().is_err());
+    }
+
+    #[test]
+    fn test_abnormal_missing_must_not_exceed_max_missing() {
+        let mut cfg = Config::default();
+        cfg.abnormal_leader_missing_duration =
+            ReadableDuration::secs(cfg.max_leader_missing_duration.as_secs() + 1);
+        assert!(cfg.validate().is_err());
+    }
+}
@@ -0,0 +1,5 @@
+// Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
+
+pub mod peer;
+
+pub use self::peer::{Peer, StaleState};
@@ -0,0 +1,257 @@
+// Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
+
+// This file only carries the hibernate-region slice of `PeerFsmDelegate`'s raft base
+// tick handling; proposal/apply/snapshot handling lives in the rest of this module.
+
+use std::time::Duration;
+
+use crate::store::config::Config;
+use crate::store::hibernate_state::{GroupState, HibernateState};
+
+/// What a follower should do about its leader's silence, checked every
+/// `stale_state_check_interval` against how long it's been since the leader was last
+/// heard from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleState {
+    /// Still within `abnormal_leader_missing_duration`; nothing to do.
+    Valid,
+    /// Past `abnormal_leader_missing_duration` but not yet `max_leader_missing_duration`:
+    /// ask PD whether the leader is still valid before doing anything more drastic, since
+    /// a hibernating leader is *expected* to be silent this long.
+    ToValidate,
+    /// Past `max_leader_missing_duration`: treat the leader as genuinely missing and
+    /// start campaigning.
+    LeaderMissing,
+}
+
+/// The subset of `Peer` state the hibernate-region tick logic needs: whether it's the
+/// leader, whether it has anything pending, and how caught-up its followers are.
+pub struct Peer {
+    pub is_leader: bool,
+    pub hibernate_state: HibernateState,
+    pending_proposals: usize,
+    pending_reads: usize,
+    /// `matched` index of every other peer in the region, as tracked by the leader.
+    peer_matched: Vec<u64>,
+    last_index: u64,
+}
+
+impl Peer {
+    pub fn new() -> Self {
+        Self {
+            is_leader: false,
+            hibernate_state: HibernateState::ordered(),
+            pending_proposals: 0,
+            pending_reads: 0,
+            peer_matched: Vec::new(),
+            last_index: 0,
+        }
+    }
+
+    /// The leader is idle when it has nothing outstanding to propose or read, and every
+    /// follower has already replicated up to `last_index`.
+    fn leader_is_idle(&self) -> bool {
+        self.pending_proposals == 0
+            && self.pending_reads == 0
+            && self
+                .peer_matched
+                .iter()
+                .all(|matched| *matched == self.last_index)
+    }
+
+    /// Runs once per raft base tick. Returns `true` if the tick should be rescheduled
+    /// (i.e. the peer is still awake), `false` if the leader may stop firing it.
+    /// Followers always keep ticking at the normal cadence; hibernation only changes how
+    /// aggressively *they* check for a missing leader (see `stale_state_check_interval`
+    /// and `check_stale_state`).
+    pub fn on_raft_base_tick(&mut self) -> bool {
+        if !self.is_leader {
+            // Track the follower's own quiet ticks so `stale_state_check_interval` can back
+            // off once nothing has arrived for a while; any incoming message wakes it again.
+            let idle = self.pending_proposals == 0 && self.pending_reads == 0;
+            self.hibernate_state.tick(idle);
+            return true;
+        }
+
+        let idle = self.leader_is_idle();
+        match self.hibernate_state.tick(idle) {
+            GroupState::Idle => false,
+            GroupState::Chaos | GroupState::PreChaos => true,
+        }
+    }
+
+    /// How often a follower re-checks `check_stale_state`. While the group is idle this
+    /// backs off to `peer_stale_state_check_interval` instead of the normal tick cadence,
+    /// so a hibernating leader's silence isn't mistaken for an outage by a follower
+    /// that's still checking as if nothing had changed.
+    pub fn stale_state_check_interval(&self, cfg: &Config) -> Duration {
+        match self.hibernate_state.group_state() {
+            GroupState::Idle => cfg.peer_stale_state_check_interval.0,
+            GroupState::Chaos | GroupState::PreChaos => cfg.raft_base_tick_interval.0,
+        }
+    }
+
+    /// A follower's verdict on how long it's gone without hearing from its leader,
+    /// governed by `abnormal_leader_missing_duration` and `max_leader_missing_duration`.
+    pub fn check_stale_state(&self, since_last_heard_from_leader: Duration, cfg: &Config) -> StaleState {
+        if since_last_heard_from_leader >= cfg.max_leader_missing_duration.0 {
+            StaleState::LeaderMissing
+        } else if since_last_heard_from_leader >= cfg.abnormal_leader_missing_duration.0 {
+            StaleState::ToValidate
+        } else {
+            StaleState::Valid
+        }
+    }
+
+    /// Called whenever the peer handles an incoming raft message, a new proposal, or a
+    /// read request. Any of these must wake a hibernating region back up, and the leader
+    /// must resume firing its base tick.
+    pub fn wake_up(&mut self) {
+        self.hibernate_state.reset(GroupState::Chaos);
+    }
+
+    /// Called on every role change. A freshly elected/changed leader must start awake
+    /// rather than inheriting whatever hibernation state the region was previously in.
+    pub fn on_role_changed(&mut self, is_leader: bool) {
+        self.is_leader = is_leader;
+        self.wake_up();
+    }
+
+    pub fn propose(&mut self) {
+        self.pending_proposals += 1;
+        self.wake_up();
+    }
+
+    pub fn propose_done(&mut self) {
+        self.pending_proposals = self.pending_proposals.saturating_sub(1);
+    }
+
+    pub fn read(&mut self) {
+        self.pending_reads += 1;
+        self.wake_up();
+    }
+
+    pub fn read_done(&mut self) {
+        self.pending_reads = self.pending_reads.saturating_sub(1);
+    }
+
+    pub fn step(&mut self) {
+        self.wake_up();
+    }
+
+    pub fn set_peer_matched(&mut self, matched: Vec<u64>, last_index: u64) {
+        self.peer_matched = matched;
+        self.last_index = last_index;
+    }
+}
+
+impl Default for Peer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leader_caught_up() -> Peer {
+        let mut peer = Peer::new();
+        peer.on_role_changed(true);
+        peer.set_peer_matched(vec![5, 5], 5);
+        peer
+    }
+
+    #[test]
+    fn test_leader_hibernates_when_idle() {
+        let mut peer = leader_caught_up();
+
+        // Two quiet ticks are enough to hibernate (see `HIBERNATE_AFTER_QUIET_TICKS`).
+        assert!(peer.on_raft_base_tick());
+        assert!(!peer.on_raft_base_tick());
+        assert!(peer.hibernate_state.is_hibernated());
+    }
+
+    #[test]
+    fn test_pending_proposal_keeps_it_awake() {
+        let mut peer = leader_caught_up();
+        peer.propose();
+
+        assert!(peer.on_raft_base_tick());
+        assert!(!peer.hibernate_state.is_hibernated());
+    }
+
+    #[test]
+    fn test_write_wakes_hibernated_region() {
+        let mut peer = leader_caught_up();
+        peer.on_raft_base_tick();
+        peer.on_raft_base_tick();
+        assert!(peer.hibernate_state.is_hibernated());
+
+        peer.propose();
+        assert!(!peer.hibernate_state.is_hibernated());
+        peer.propose_done();
+        // Needs to requalify for hibernation; a single tick isn't enough yet.
+        assert!(peer.on_raft_base_tick());
+        assert!(!peer.hibernate_state.is_hibernated());
+    }
+
+    #[test]
+    fn test_new_leader_starts_awake() {
+        let mut peer = leader_caught_up();
+        peer.on_raft_base_tick();
+        peer.on_raft_base_tick();
+        assert!(peer.hibernate_state.is_hibernated());
+
+        peer.on_role_changed(true);
+        assert!(!peer.hibernate_state.is_hibernated());
+    }
+
+    #[test]
+    fn test_lagging_follower_keeps_leader_awake() {
+        let mut peer = leader_caught_up();
+        peer.set_peer_matched(vec![5, 4], 5);
+
+        assert!(peer.on_raft_base_tick());
+        assert!(peer.on_raft_base_tick());
+        assert!(!peer.hibernate_state.is_hibernated());
+    }
+
+    #[test]
+    fn test_check_stale_state_thresholds() {
+        let peer = Peer::new();
+        let cfg = Config::default();
+
+        assert_eq!(
+            peer.check_stale_state(Duration::from_secs(1), &cfg),
+            StaleState::Valid
+        );
+        assert_eq!(
+            peer.check_stale_state(cfg.abnormal_leader_missing_duration.0, &cfg),
+            StaleState::ToValidate
+        );
+        assert_eq!(
+            peer.check_stale_state(cfg.max_leader_missing_duration.0, &cfg),
+            StaleState::LeaderMissing
+        );
+    }
+
+    #[test]
+    fn test_stale_state_check_interval_backs_off_once_hibernated() {
+        let mut peer = leader_caught_up();
+        let cfg = Config::default();
+
+        assert_eq!(
+            peer.stale_state_check_interval(&cfg),
+            cfg.raft_base_tick_interval.0
+        );
+
+        peer.on_raft_base_tick();
+        peer.on_raft_base_tick();
+        assert!(peer.hibernate_state.is_hibernated());
+        assert_eq!(
+            peer.stale_state_check_interval(&cfg),
+            cfg.peer_stale_state_check_interval.0
+        );
+    }
+}
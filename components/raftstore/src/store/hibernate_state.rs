@@ -0,0 +1,132 @@
+// Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
+
+/// How "busy" a peer's region currently is, used to decide whether the peer can stop
+/// paying the normal ticking cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupState {
+    /// The region just became active (e.g. split/merge/conf change/new leader) and
+    /// hasn't proven itself quiet yet. Tick at the normal cadence.
+    Chaos,
+    /// The region has stopped seeing proposals/reads and every follower has caught up,
+    /// but we haven't seen enough consecutive quiet ticks to hibernate yet.
+    PreChaos,
+    /// The region is idle: the leader may stop firing the raft base tick (so it stops
+    /// sending heartbeats) and followers may fall back to the longer stale-state checks.
+    Idle,
+}
+
+/// How many consecutive quiet ticks are required before a region is allowed to move
+/// from `PreChaos` into `Idle`. This absorbs one-off blips (e.g. a single read that
+/// raced with the idle check) instead of hibernating and immediately waking back up.
+pub const HIBERNATE_AFTER_QUIET_TICKS: u32 = 2;
+
+/// Tracks whether a peer's region is busy enough to require normal ticking, or quiet
+/// enough that ticking (and, for followers, aggressive leader-missing checks) can be
+/// relaxed.
+///
+/// A fresh peer, and any peer that just became leader or observed a leader change,
+/// always starts in `Chaos` so it never hibernates before proving itself idle.
+#[derive(Debug, Clone)]
+pub struct HibernateState {
+    group_state: GroupState,
+    quiet_ticks: u32,
+}
+
+impl HibernateState {
+    pub fn ordered() -> Self {
+        Self {
+            group_state: GroupState::Chaos,
+            quiet_ticks: 0,
+        }
+    }
+
+    pub fn group_state(&self) -> GroupState {
+        self.group_state
+    }
+
+    /// Called once per raft base tick with whether the peer looked idle *this* tick
+    /// (leader: no pending proposals/reads and every follower's `matched` caught up to
+    /// `last_index`; follower: nothing to report to the leader). Returns the resulting
+    /// `GroupState`.
+    pub fn tick(&mut self, is_idle: bool) -> GroupState {
+        if !is_idle {
+            self.reset(GroupState::Chaos);
+            return self.group_state;
+        }
+
+        match self.group_state {
+            GroupState::Chaos => {
+                self.group_state = GroupState::PreChaos;
+                self.quiet_ticks = 1;
+            }
+            GroupState::PreChaos => {
+                self.quiet_ticks += 1;
+                if self.quiet_ticks >= HIBERNATE_AFTER_QUIET_TICKS {
+                    self.group_state = GroupState::Idle;
+                }
+            }
+            GroupState::Idle => {}
+        }
+        self.group_state
+    }
+
+    /// Any incoming message, proposal, or read wakes the region back up immediately.
+    /// A freshly elected/changed leader should also call this so it starts awake.
+    pub fn reset(&mut self, state: GroupState) {
+        self.group_state = state;
+        self.quiet_ticks = 0;
+    }
+
+    pub fn is_hibernated(&self) -> bool {
+        self.group_state == GroupState::Idle
+    }
+}
+
+impl Default for HibernateState {
+    fn default() -> Self {
+        Self::ordered()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hibernates_after_enough_quiet_ticks() {
+        let mut state = HibernateState::ordered();
+        assert_eq!(state.group_state(), GroupState::Chaos);
+
+        assert_eq!(state.tick(true), GroupState::PreChaos);
+        assert!(!state.is_hibernated());
+        assert_eq!(state.tick(true), GroupState::Idle);
+        assert!(state.is_hibernated());
+    }
+
+    #[test]
+    fn test_activity_wakes_and_resets_quiet_count() {
+        let mut state = HibernateState::ordered();
+        state.tick(true);
+        state.tick(true);
+        assert!(state.is_hibernated());
+
+        // A single busy tick (incoming message/proposal/read) wakes it straight back up.
+        assert_eq!(state.tick(false), GroupState::Chaos);
+        assert!(!state.is_hibernated());
+
+        // And it needs to requalify for hibernation from scratch.
+        assert_eq!(state.tick(true), GroupState::PreChaos);
+    }
+
+    #[test]
+    fn test_reset_wakes_region_for_new_leader() {
+        let mut state = HibernateState::ordered();
+        state.tick(true);
+        state.tick(true);
+        assert!(state.is_hibernated());
+
+        // A newly elected/changed leader must start awake.
+        state.reset(GroupState::Chaos);
+        assert_eq!(state.group_state(), GroupState::Chaos);
+    }
+}
@@ -0,0 +1,8 @@
+// Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
+
+pub mod config;
+pub mod fsm;
+pub mod hibernate_state;
+
+pub use self::config::Config;
+pub use self::hibernate_state::{GroupState, HibernateState};
@@ -0,0 +1,23 @@
+// Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
+
+use std::time::Duration;
+
+use tikv_util::config::ReadableDuration;
+
+use crate::{Cluster, Simulator};
+
+/// Mirrors `configure_for_merge`: tune the raft-store knobs so a test can directly
+/// observe a region hibernating (and staying hibernated) rather than racing it.
+///
+/// Hibernation itself needs the base tick to actually stop firing, so we don't shrink
+/// `raft_base_tick_interval` the way some merge tests do; instead we stretch the
+/// follower-side stale-state knobs way out, in the same ratio `Config::validate`
+/// requires (`peer_stale_state_check_interval` shorter than
+/// `abnormal_leader_missing_duration`, which is in turn `<= max_leader_missing_duration`),
+/// so a follower never mistakes its hibernating leader's silence for a real outage
+/// during the test.
+pub fn configure_for_hibernate<T: Simulator>(cluster: &mut Cluster<T>) {
+    cluster.cfg.raft_store.peer_stale_state_check_interval = ReadableDuration(Duration::from_secs(1800));
+    cluster.cfg.raft_store.abnormal_leader_missing_duration = ReadableDuration(Duration::from_secs(3600));
+    cluster.cfg.raft_store.max_leader_missing_duration = ReadableDuration(Duration::from_secs(7200));
+}
@@ -0,0 +1,11 @@
+// Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
+
+// `Cluster`, `Simulator`, `RegionPacketFilter`, `CloneFilterFactory`,
+// `IsolationFilterFactory`, and `LeadingDuplicatedSnapshotFilter` live in sibling
+// modules of this crate that aren't part of this change.
+
+pub mod cluster;
+pub mod transport_simulate;
+
+pub use self::cluster::configure_for_hibernate;
+pub use self::transport_simulate::{DelayFilter, DropMessageFilter, Filter, RandomLossFilter};
@@ -6,7 +6,9 @@ use std::sync::Arc;
 
 use tikv_util::buffer_vec::BufferVec;
 
+use crate::codec::collation::{match_template_collator, Collator};
 use crate::codec::Result;
+use crate::Collation;
 
 #[derive(Clone, Debug)]
 pub struct Enum {
@@ -14,11 +16,17 @@ pub struct Enum {
 
     // MySQL Enum is 1-based index, value == 0 means this enum is ''
     value: usize,
+
+    collation: Collation,
 }
 
 impl Enum {
-    pub fn new(data: Arc<BufferVec>, value: usize) -> Self {
-        Self { data, value }
+    pub fn new(data: Arc<BufferVec>, value: usize, collation: Collation) -> Self {
+        Self {
+            data,
+            value,
+            collation,
+        }
     }
     pub fn value(&self) -> usize {
         self.value
@@ -27,6 +35,7 @@ impl Enum {
         EnumRef {
             data: &self.data,
             value: self.value,
+            collation: self.collation,
         }
     }
 }
@@ -71,16 +80,22 @@ impl crate::codec::data_type::AsMySQLBool for Enum {
 pub struct EnumRef<'a> {
     data: &'a BufferVec,
     value: usize,
+    collation: Collation,
 }
 
 impl<'a> EnumRef<'a> {
-    pub fn new(data: &'a BufferVec, value: usize) -> Self {
-        Self { data, value }
+    pub fn new(data: &'a BufferVec, value: usize, collation: Collation) -> Self {
+        Self {
+            data,
+            value,
+            collation,
+        }
     }
     pub fn to_owned(self) -> Enum {
         Enum {
             data: Arc::new(self.data.clone()),
             value: self.value,
+            collation: self.collation,
         }
     }
     pub fn is_empty(&self) -> bool {
@@ -89,15 +104,72 @@ impl<'a> EnumRef<'a> {
     pub fn value(&self) -> usize {
         self.value
     }
+
+    /// Returns this enum's member name, with any insignificant trailing padding stripped
+    /// according to `self.collation`'s PAD SPACE rule (the same rule `cmp_str`/`eq_str`
+    /// already honor when comparing).
     pub fn as_str(&self) -> Result<&str> {
         if self.value == 0 {
             return Ok("");
         }
 
         let buf = &self.data[self.value - 1];
+        let s = std::str::from_utf8(buf)?;
+        Ok(Self::trim_padding(self.collation, s))
+    }
+
+    /// Compares this enum's member name against a raw string under `self.collation`.
+    ///
+    /// This is used whenever an enum is compared against (or looked up by) a string, where
+    /// the comparison has to go through collation-aware key ordering rather than raw bytes.
+    /// Enum-vs-enum comparison should keep using the numeric fast path on `value` instead.
+    pub fn cmp_str(&self, s: &[u8]) -> Result<Ordering> {
+        let lhs = self.as_str()?.as_bytes();
+        let collation = self.collation;
+        Ok(match_template_collator! {
+            TT, match collation {
+                Collation::TT => TT::sort_compare(lhs, s)?,
+            }
+        })
+    }
+
+    /// Returns whether `s` resolves, under `self.collation`, to the member this enum
+    /// currently holds.
+    pub fn eq_str(&self, s: &[u8]) -> Result<bool> {
+        Ok(self.cmp_str(s)? == Ordering::Equal)
+    }
+
+    /// Finds the 1-based index of the member that `s` resolves to under `collation`,
+    /// or `None` if no member matches.
+    pub fn index_of(data: &BufferVec, collation: Collation, s: &[u8]) -> Result<Option<usize>> {
+        for i in 0..data.len() {
+            let matched = match_template_collator! {
+                TT, match collation {
+                    Collation::TT => TT::sort_compare(&data[i], s)? == Ordering::Equal,
+                }
+            };
+            if matched {
+                return Ok(Some(i + 1));
+            }
+        }
+        Ok(None)
+    }
 
-        // TODO: take string collation into consideration here.
-        Ok(std::str::from_utf8(buf)?)
+    /// Strips insignificant trailing spaces from `s` when `collation` is a PAD SPACE
+    /// collation; returns `s` unchanged under a NO PAD collation. Used by both `as_str`
+    /// and `Display` so rendering and comparison agree on what counts as padding.
+    fn trim_padding(collation: Collation, s: &str) -> &str {
+        match_template_collator! {
+            TT, match collation {
+                Collation::TT => {
+                    if TT::IS_PADDING {
+                        s.trim_end_matches(' ')
+                    } else {
+                        s
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -107,10 +179,8 @@ impl<'a> Display for EnumRef<'a> {
             return Ok(());
         }
 
-        let buf = &self.data[self.value - 1];
-
         // TODO: Check the requirements and intentions of to_string usage.
-        write!(f, "{}", String::from_utf8_lossy(buf))
+        write!(f, "{}", self.as_str().unwrap_or_default())
     }
 }
 
@@ -151,6 +221,7 @@ mod tests {
             let e = Enum {
                 data: Arc::new(buf),
                 value,
+                collation: Collation::Binary,
             };
 
             assert_eq!(e.to_string(), expect.to_string())
@@ -167,7 +238,11 @@ mod tests {
                 buf.push(v)
             }
 
-            let e = EnumRef { data: &buf, value };
+            let e = EnumRef {
+                data: &buf,
+                value,
+                collation: Collation::Binary,
+            };
 
             assert_eq!(e.as_str().expect("get str correctly"), expect)
         }
@@ -183,6 +258,7 @@ mod tests {
         let s = Enum {
             data: Arc::new(buf),
             value: 1,
+            collation: Collation::Binary,
         };
 
         assert!(!s.as_ref().is_empty());
@@ -190,8 +266,81 @@ mod tests {
         let s = Enum {
             data: s.data,
             value: 0,
+            collation: Collation::Binary,
         };
 
         assert!(s.as_ref().is_empty());
     }
+
+    #[test]
+    fn test_cmp_str_case_insensitive() {
+        let mut buf = BufferVec::new();
+        for v in &["Red", "Green", "Blue"] {
+            buf.push(v)
+        }
+        let buf = Arc::new(buf);
+
+        let red = EnumRef {
+            data: &buf,
+            value: 1,
+            collation: Collation::Utf8Mb4GeneralCi,
+        };
+
+        // utf8_general_ci: case differences don't affect equality or ordering.
+        assert!(red.eq_str(b"RED").unwrap());
+        assert!(red.eq_str(b"red").unwrap());
+        assert_eq!(red.cmp_str(b"red").unwrap(), Ordering::Equal);
+
+        // A binary (case-sensitive) collation must tell them apart.
+        let red_bin = EnumRef {
+            data: &buf,
+            value: 1,
+            collation: Collation::Binary,
+        };
+        assert!(!red_bin.eq_str(b"RED").unwrap());
+        assert_eq!(red_bin.cmp_str(b"RED").unwrap(), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_index_of_case_insensitive() {
+        let mut buf = BufferVec::new();
+        for v in &["Red", "Green", "Blue"] {
+            buf.push(v)
+        }
+
+        assert_eq!(
+            EnumRef::index_of(&buf, Collation::Utf8Mb4GeneralCi, b"GREEN").unwrap(),
+            Some(2)
+        );
+        assert_eq!(
+            EnumRef::index_of(&buf, Collation::Utf8Mb4GeneralCi, b"purple").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_display_and_as_str_strip_padding_under_pad_space_collation() {
+        let mut buf = BufferVec::new();
+        buf.push("red  ");
+        let buf = Arc::new(buf);
+
+        // utf8_general_ci is a PAD SPACE collation: trailing spaces are insignificant and
+        // shouldn't show up when the member is rendered or read back as a string.
+        let padded = EnumRef {
+            data: &buf,
+            value: 1,
+            collation: Collation::Utf8Mb4GeneralCi,
+        };
+        assert_eq!(padded.as_str().unwrap(), "red");
+        assert_eq!(padded.to_string(), "red");
+
+        // A NO PAD (binary) collation keeps trailing spaces significant.
+        let unpadded = EnumRef {
+            data: &buf,
+            value: 1,
+            collation: Collation::Binary,
+        };
+        assert_eq!(unpadded.as_str().unwrap(), "red  ");
+        assert_eq!(unpadded.to_string(), "red  ");
+    }
 }
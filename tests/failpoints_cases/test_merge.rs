@@ -520,4 +520,62 @@ fn test_node_merge_restart_after_apply_premerge_before_apply_compact_log() {
     }
     cluster.must_put(b"k123", b"v2");
     must_get_equal(&cluster.get_engine(3), b"k123", b"v2");
+}
+
+// Test that a write still commits on every peer after the region has sat idle
+// long enough to hibernate (the leader stops sending raft base tick heartbeats).
+// Hibernation itself is not observed here.
+// See `raftstore::store::hibernate_state` for the tick-suppression logic and
+// `test_raftstore::configure_for_hibernate` for the helper this test uses.
+#[test]
+fn test_node_hibernate_region_wake_on_write() {
+    let _guard = ::setup();
+    let mut cluster = new_node_cluster(0, 3);
+    configure_for_hibernate(&mut cluster);
+    cluster.run();
+
+    cluster.must_put(b"k1", b"v1");
+    must_get_equal(&cluster.get_engine(1), b"k1", b"v1");
+
+    // `raft_base_tick_interval` is left at its 1s default (see `configure_for_hibernate`),
+    // and the leader needs `HIBERNATE_AFTER_QUIET_TICKS` (2) consecutive quiet ticks
+    // before it stops ticking, so give it a few ticks' worth of idle time with margin
+    // rather than racing the exact threshold.
+    thread::sleep(Duration::from_secs(5));
+
+    // A write must wake the region back up and still commit on all peers.
+    cluster.must_put(b"k2", b"v2");
+    for i in 1..=3 {
+        must_get_equal(&cluster.get_engine(i), b"k2", b"v2");
+    }
+}
+
+// Test that a plain "drop every MsgAppend to store 3" filter is enough to
+// keep a write from reaching that peer, without building a full
+// `RegionPacketFilter`, and that it catches up once the filter is cleared.
+// See `test_raftstore::transport_simulate::DropMessageFilter`.
+#[test]
+fn test_node_drop_message_filter_isolates_store() {
+    let _guard = ::setup();
+
+    let mut cluster = new_node_cluster(0, 3);
+    configure_for_merge(&mut cluster);
+    let pd_client = Arc::clone(&cluster.pd_client);
+    pd_client.disable_default_operator();
+    cluster.run();
+
+    cluster.must_put(b"k1", b"v1");
+    cluster.must_put(b"k3", b"v3");
+
+    // Drop all MsgAppend sent to store 3, regardless of which region they
+    // belong to, instead of scoping the filter to a single region.
+    cluster.add_send_filter(CloneFilterFactory(
+        DropMessageFilter::new(MessageType::MsgAppend).store(3),
+    ));
+
+    cluster.must_put(b"k9", b"v9");
+    must_get_none(&cluster.get_engine(3), b"k9");
+
+    cluster.clear_send_filters();
+    must_get_equal(&cluster.get_engine(3), b"k9", b"v9");
 }
\ No newline at end of file